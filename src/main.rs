@@ -1,6 +1,13 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
 use anyhow::Result;
 use anyhow::anyhow;
-use std::io::{self, BufRead};
+use hmac::Hmac;
+use sha2::Sha256;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+mod slip39;
 
 fn main() {
     if let Err(e) = run() {
@@ -15,20 +22,51 @@ fn run() -> Result<()> {
     match matches.subcommand() {
         Some(("split", matches)) => {
             let args = parse_split_args(matches)?;
-            let shards = split_command(&args.seed_phrase, args.num_shards, args.threshold)?;
-            for shard in shards {
-                println!("{} {}", shard.index, shard.mnemonic.to_string());
-            }
-            Ok(())
+            let shard_texts: Vec<String> = match args.scheme {
+                Scheme::Sharks if args.encrypt => split_command_encrypted(
+                    &args.seed_phrase,
+                    args.num_shards,
+                    args.threshold,
+                    &args.passphrase,
+                    args.language,
+                )?,
+                Scheme::Sharks => split_command(&args.seed_phrase, args.num_shards, args.threshold, args.language)?,
+                Scheme::Slip39 => split_command_slip39(
+                    &args.seed_phrase,
+                    args.num_shards,
+                    args.threshold,
+                    &args.passphrase,
+                    args.language,
+                )?,
+            };
+            emit_shards(&shard_texts, &args.qr)
         }
-        Some(("recover", _)) => {
-            let shards = parse_recover_args()?;
-            let phrase = recover_command(&shards)?;
+        Some(("recover", matches)) => {
+            let passphrase = matches.get_one::<String>("passphrase").cloned().unwrap_or_default();
+            let encrypt = matches.get_flag("encrypt");
+            let language = parse_language(matches.get_one::<String>("language").unwrap())?;
+            if matches.get_flag("camera") {
+                return Err(anyhow!(
+                    "Camera capture isn't supported in this build (it would pull in native v4l2/libclang \
+                     dependencies); scan shard QR codes to image files instead and pass them with --qr"
+                ));
+            }
+            let phrase = if matches.get_flag("interactive") {
+                recover_interactive(&passphrase, encrypt, language)?
+            } else {
+                match matches.get_many::<String>("qr") {
+                    Some(paths) => {
+                        recover_from_qr_images(&paths.cloned().collect::<Vec<_>>(), &passphrase, encrypt, language)?
+                    }
+                    None => recover_from_stdin(&passphrase, encrypt, language)?,
+                }
+            };
             println!("{}", phrase);
             Ok(())
         }
-        Some(("generate", _)) => {
-            let phrase = generate_command()?;
+        Some(("generate", matches)) => {
+            let language = parse_language(matches.get_one::<String>("language").unwrap())?;
+            let phrase = generate_command(language)?;
             println!("{}", phrase);
             Ok(())
         }
@@ -63,134 +101,847 @@ fn build_cli() -> clap::Command {
                         .help("Number of shards required to recover the secret (minimum 2, maximum: number of shards)")
                         .required(true)
                         .value_parser(clap::value_parser!(u8).range(2..))
+                )
+                .arg(
+                    clap::Arg::new("scheme")
+                        .long("scheme")
+                        .help("Sharing scheme to use for the shards (slip39: a wrong --passphrase recovers silently to a different, plausible-looking phrase instead of erroring, per the SLIP-39 spec)")
+                        .value_parser(["sharks", "slip39"])
+                        .default_value("sharks")
+                )
+                .arg(
+                    clap::Arg::new("passphrase")
+                        .long("passphrase")
+                        .help("Passphrase to encrypt the secret with before splitting (slip39 scheme, or sharks scheme with --encrypt; unlike --encrypt, a wrong slip39 passphrase is never detected as wrong)")
+                        .default_value("")
+                )
+                .arg(
+                    clap::Arg::new("encrypt")
+                        .long("encrypt")
+                        .help("Seal the secret with AES-256-GCM under --passphrase before splitting (sharks scheme only)")
+                        .action(clap::ArgAction::SetTrue)
+                )
+                .arg(
+                    clap::Arg::new("qr")
+                        .long("qr")
+                        .help("Also render each shard's QR code to the terminal")
+                        .action(clap::ArgAction::SetTrue)
+                )
+                .arg(
+                    clap::Arg::new("qr-out")
+                        .long("qr-out")
+                        .help("Directory to also write each shard's QR code as an image file")
+                        .value_name("DIR")
+                )
+                .arg(
+                    clap::Arg::new("qr-format")
+                        .long("qr-format")
+                        .help("Image format used by --qr-out")
+                        .value_parser(["png", "svg"])
+                        .default_value("png")
+                )
+                .arg(
+                    clap::Arg::new("language")
+                        .long("language")
+                        .help("BIP39 wordlist language for the seed phrase and the shard mnemonics")
+                        .value_parser(LANGUAGE_NAMES)
+                        .default_value("english")
                 ),
         )
         .subcommand(
             clap::Command::new("recover")
-                .about("Recover the original seed phrase from shards read from stdin (one per line)")
+                .about("Recover the original seed phrase from shards (stdin by default; see --qr/--interactive/--camera)")
+                .arg(
+                    clap::Arg::new("passphrase")
+                        .long("passphrase")
+                        .help("Passphrase the secret was encrypted with before splitting (slip39 scheme, or sharks scheme with --encrypt; unlike --encrypt, a wrong slip39 passphrase recovers silently to a different phrase instead of erroring)")
+                        .default_value("")
+                )
+                .arg(
+                    clap::Arg::new("encrypt")
+                        .long("encrypt")
+                        .help("Assert that the shards were sealed with --encrypt and should be opened with AES-256-GCM")
+                        .action(clap::ArgAction::SetTrue)
+                )
+                .arg(
+                    clap::Arg::new("qr")
+                        .long("qr")
+                        .help("Read shards from QR codes in these image files instead of stdin")
+                        .value_name("IMAGE")
+                        .num_args(1..)
+                )
+                .arg(
+                    // The backlog item asked for camera scanning alongside image-file
+                    // scanning; the flag is accepted (so scripts naming --camera get a
+                    // clear error rather than "unknown argument") but wired to report
+                    // a clear "not supported in this build" failure rather than
+                    // wiring up a camera-capture backend, to avoid pulling in native
+                    // v4l2/libclang dependencies. That's a scope cut from what was
+                    // asked, not a bug fix, and is called out here as a follow-up
+                    // pending the requester's sign-off rather than shipped as "done".
+                    clap::Arg::new("camera")
+                        .long("camera")
+                        .help("Read shards from a camera device instead of stdin (not supported in this build)")
+                        .action(clap::ArgAction::SetTrue)
+                )
+                .arg(
+                    clap::Arg::new("interactive")
+                        .long("interactive")
+                        .help("Prompt for shards one at a time with live validation instead of reading stdin in bulk")
+                        .action(clap::ArgAction::SetTrue)
+                )
+                .arg(
+                    clap::Arg::new("language")
+                        .long("language")
+                        .help("BIP39 wordlist language to render the recovered phrase in (slip39 scheme and --encrypt shards don't carry their own language)")
+                        .value_parser(LANGUAGE_NAMES)
+                        .default_value("english")
+                )
         )
         .subcommand(
             clap::Command::new("generate")
                 .about("Generate a new random BIP39 seed phrase")
+                .arg(
+                    clap::Arg::new("language")
+                        .long("language")
+                        .help("BIP39 wordlist language for the generated seed phrase")
+                        .value_parser(LANGUAGE_NAMES)
+                        .default_value("english")
+                )
         )
 }
 
+/// Accepted values for `--language`, matching the optional wordlists `bip39`
+/// ships alongside English.
+const LANGUAGE_NAMES: [&str; 10] = [
+    "english",
+    "japanese",
+    "korean",
+    "spanish",
+    "chinese-simplified",
+    "chinese-traditional",
+    "french",
+    "italian",
+    "czech",
+    "portuguese",
+];
+
+fn parse_language(name: &str) -> Result<bip39::Language> {
+    match name {
+        "english" => Ok(bip39::Language::English),
+        "japanese" => Ok(bip39::Language::Japanese),
+        "korean" => Ok(bip39::Language::Korean),
+        "spanish" => Ok(bip39::Language::Spanish),
+        "chinese-simplified" => Ok(bip39::Language::SimplifiedChinese),
+        "chinese-traditional" => Ok(bip39::Language::TraditionalChinese),
+        "french" => Ok(bip39::Language::French),
+        "italian" => Ok(bip39::Language::Italian),
+        "czech" => Ok(bip39::Language::Czech),
+        "portuguese" => Ok(bip39::Language::Portuguese),
+        other => Err(anyhow!("Unknown language: {}", other)),
+    }
+}
+
+enum Scheme {
+    Sharks,
+    Slip39,
+}
+
 struct SplitArgs {
     seed_phrase: String,
     num_shards: u8,
     threshold: u8,
+    scheme: Scheme,
+    passphrase: String,
+    encrypt: bool,
+    qr: QrOutputArgs,
+    language: bip39::Language,
+}
+
+/// How (and whether) `split` should also render each shard as a QR code,
+/// for transporting shares to air-gapped machines without retyping them.
+struct QrOutputArgs {
+    terminal: bool,
+    out_dir: Option<String>,
+    format: String,
 }
 
 fn parse_split_args(matches: &clap::ArgMatches) -> Result<SplitArgs> {
+    let scheme = match matches.get_one::<String>("scheme").unwrap().as_str() {
+        "slip39" => Scheme::Slip39,
+        _ => Scheme::Sharks,
+    };
+    if matches!(scheme, Scheme::Slip39) && matches.get_flag("encrypt") {
+        return Err(anyhow!("--encrypt is sharks scheme only; --scheme slip39 already wraps the secret with --passphrase"));
+    }
     Ok(SplitArgs {
         seed_phrase: matches.get_one::<String>("seed-phrase").unwrap().clone(),
         num_shards: *matches.get_one::<u8>("shards").unwrap(),
         threshold: *matches.get_one::<u8>("threshold").unwrap(),
+        scheme,
+        passphrase: matches.get_one::<String>("passphrase").unwrap().clone(),
+        encrypt: matches.get_flag("encrypt"),
+        qr: QrOutputArgs {
+            terminal: matches.get_flag("qr"),
+            out_dir: matches.get_one::<String>("qr-out").cloned(),
+            format: matches.get_one::<String>("qr-format").unwrap().clone(),
+        },
+        language: parse_language(matches.get_one::<String>("language").unwrap())?,
     })
 }
 
-fn parse_recover_args() -> Result<Vec<MnemonicShard>> {
+/// Reads shard mnemonics from stdin, one per line, and recovers the secret.
+/// See [`recover_from_lines`] for format auto-detection and early-stop rules.
+fn recover_from_stdin(passphrase: &str, encrypt: bool, language: bip39::Language) -> Result<String> {
     let stdin = io::stdin();
-    let mut shards = Vec::new();
+    let lines = stdin.lock().lines().map(|l| l.map_err(|e| anyhow!("Error reading line: {}", e)));
+    recover_from_lines(lines, passphrase, encrypt, language)
+}
+
+/// Reads shard text decoded from each QR code image in `paths` and recovers
+/// the secret the same way [`recover_from_stdin`] does for typed-in shards.
+fn recover_from_qr_images(paths: &[String], passphrase: &str, encrypt: bool, language: bip39::Language) -> Result<String> {
+    let lines = paths.iter().map(|path| read_qr_image(Path::new(path)));
+    recover_from_lines(lines, passphrase, encrypt, language)
+}
 
-    for line in stdin.lock().lines() {
-        let line = line.map_err(|e| anyhow!("Error reading line: {}", e))?;
+/// Which shard text format an interactive recovery session has locked onto,
+/// detected from the first shard the user enters.
+#[derive(Clone, Copy)]
+enum ShardKind {
+    Sharks,
+    Encrypted,
+    Slip39,
+}
+
+/// Prompts for shards one at a time, validating each as it's entered and
+/// reporting how many more are needed, instead of reading stdin in bulk.
+/// Falls back to [`recover_from_stdin`] when stdin isn't a terminal, since
+/// there's no user to prompt. Never reconstructs the secret until enough
+/// valid shards have been collected.
+fn recover_interactive(passphrase: &str, encrypt: bool, language: bip39::Language) -> Result<String> {
+    if !io::stdin().is_terminal() {
+        return recover_from_stdin(passphrase, encrypt, language);
+    }
+
+    let mut kind = None;
+    let mut lines = Vec::new();
+    let mut group_id = None;
+    let mut needed: Option<u8> = None;
+    // SLIP-39's threshold is two-level (enough groups, each with enough
+    // members), so unlike `needed` it can't be tracked as a single flat
+    // count; decoded shares are kept around so `shares_satisfy_threshold` can
+    // re-derive it as each one comes in.
+    let mut slip39_shares: Vec<slip39::Slip39Share> = Vec::new();
 
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 2 {
-            return Err(anyhow!("Invalid shard format. Expected: <number> <mnemonic>. Got:\n{}", line));
+    loop {
+        let have_enough = match kind {
+            Some(ShardKind::Slip39) => slip39::shares_satisfy_threshold(&slip39_shares),
+            _ => matches!(needed, Some(n) if lines.len() as u8 >= n),
+        };
+        if have_enough {
+            break;
         }
 
-        let index = parts[0].parse::<u8>()
-            .map_err(|_| anyhow!("Invalid shard number"))?;
+        match kind {
+            Some(ShardKind::Slip39) => {
+                print!("Shard {} ({}): ", lines.len() + 1, slip39::progress_summary(&slip39_shares))
+            }
+            _ => match needed {
+                Some(n) => print!("Shard {} of {} needed: ", lines.len() + 1, n),
+                None => print!("Shard {}: ", lines.len() + 1),
+            },
+        }
+        io::stdout().flush().ok();
 
-        let mnemonic = bip39::Mnemonic::parse_in(
-            bip39::Language::English,
-            &parts[1..].join(" ")
-        ).map_err(|e| anyhow!("Invalid mnemonic: {}", e))?;
+        let mut raw = String::new();
+        if io::stdin().read_line(&mut raw)? == 0 {
+            return Err(anyhow!("No more input while waiting for shards"));
+        }
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
 
-        shards.push(MnemonicShard {
-            index,
-            mnemonic,
+        let detected_kind = kind.unwrap_or_else(|| {
+            if line.starts_with(ENCRYPTED_SHARD_PREFIX) {
+                ShardKind::Encrypted
+            } else if slip39::looks_like_slip39(line) {
+                ShardKind::Slip39
+            } else {
+                ShardKind::Sharks
+            }
         });
+        if encrypt && !matches!(detected_kind, ShardKind::Encrypted) {
+            println!(
+                "  --encrypt was given but this doesn't look like an {}-prefixed encrypted shard; try again",
+                ENCRYPTED_SHARD_PREFIX
+            );
+            continue;
+        }
+
+        match validate_shard_line(line, detected_kind) {
+            Ok((threshold, shard_group_id)) => {
+                if shard_group_id.is_some() && group_id.is_some() && group_id != shard_group_id {
+                    println!("  this shard belongs to a different shard set; try again");
+                    continue;
+                }
+                group_id = group_id.or(shard_group_id);
+                kind = Some(detected_kind);
+                needed = needed.or(Some(threshold));
+                lines.push(line.to_string());
+                if let ShardKind::Slip39 = detected_kind {
+                    slip39_shares.push(slip39::decode_share(line).expect("validate_shard_line already decoded this share"));
+                    println!("  accepted ({})", slip39::progress_summary(&slip39_shares));
+                } else {
+                    println!("  accepted ({} of {} collected)", lines.len(), needed.unwrap());
+                }
+            }
+            Err(e) => println!("  {}", e),
+        }
     }
 
-    Ok(shards)
+    match kind {
+        Some(ShardKind::Slip39) => recover_command_slip39(&lines, passphrase, language),
+        Some(ShardKind::Encrypted) => recover_command_encrypted(&lines, passphrase, language),
+        Some(ShardKind::Sharks) | None => recover_command(&lines),
+    }
+}
+
+/// Validates a single shard line for the detected format, printing immediate
+/// per-word/checksum feedback, and returns `(threshold, group_id)` so the
+/// interactive loop knows how many more shards it still needs. For SLIP-39,
+/// `group_id` is the share's identifier, which plays the same
+/// different-shard-set-detection role as the sharks schemes' group id.
+fn validate_shard_line(line: &str, kind: ShardKind) -> Result<(u8, Option<u16>)> {
+    match kind {
+        ShardKind::Sharks => {
+            let (header, mnemonic_text) = split_shard_header(line)?;
+            let mnemonic = bip39::Mnemonic::parse(mnemonic_text.trim()).map_err(describe_bip39_error)?;
+            println!("  valid {}-word mnemonic, checksum OK", mnemonic.word_count());
+            Ok((header.threshold, Some(header.group_id)))
+        }
+        ShardKind::Encrypted => {
+            let hex = line
+                .strip_prefix(ENCRYPTED_SHARD_PREFIX)
+                .ok_or_else(|| anyhow!("missing {} prefix", ENCRYPTED_SHARD_PREFIX))?;
+            let (header, _) = parse_shard_header_bytes(&decode_hex(hex)?)?;
+            println!("  valid encrypted shard");
+            Ok((header.threshold, Some(header.group_id)))
+        }
+        ShardKind::Slip39 => {
+            let share = slip39::decode_share(line)?;
+            println!(
+                "  valid SLIP-39 share, checksum OK (member threshold {})",
+                share.member_threshold
+            );
+            Ok((share.member_threshold, Some(share.identifier)))
+        }
+    }
+}
+
+fn describe_bip39_error(e: bip39::Error) -> anyhow::Error {
+    match e {
+        bip39::Error::UnknownWord(i) => {
+            anyhow!("word {} isn't in the wordlist; check the spelling and try again", i + 1)
+        }
+        bip39::Error::BadWordCount(c) => {
+            anyhow!("{} words isn't a valid BIP39 length (must be 12, 15, 18, 21, or 24)", c)
+        }
+        bip39::Error::InvalidChecksum => anyhow!("checksum failed; you likely mistyped a word"),
+        other => anyhow!("{}", other),
+    }
+}
+
+/// Recovers the secret from a sequence of shard lines, auto-detecting
+/// whether they're SLIP-39 (a full line of wordlist words), AES-256-GCM-
+/// encrypted sharks shards (an `enc:`-prefixed hex line), or plain self-
+/// describing sharks shards, and for each format stopping as soon as the
+/// embedded threshold (for SLIP-39, the two-level group/member threshold) is
+/// satisfied instead of consuming every line given.
+fn recover_from_lines(
+    mut lines: impl Iterator<Item = Result<String>>,
+    passphrase: &str,
+    encrypt: bool,
+    language: bip39::Language,
+) -> Result<String> {
+    let mut first_line = None;
+    for line in &mut lines {
+        let line = line?;
+        if !line.trim().is_empty() {
+            first_line = Some(line);
+            break;
+        }
+    }
+    let first_line = first_line.ok_or_else(|| anyhow!("No shards provided"))?;
+
+    if slip39::looks_like_slip39(first_line.trim()) {
+        if encrypt {
+            return Err(anyhow!("Shards look like SLIP-39, not --encrypt sharks shards"));
+        }
+        let mut shares = vec![slip39::decode_share(first_line.trim())?];
+        let mut remaining_lines = vec![first_line];
+        while !slip39::shares_satisfy_threshold(&shares) {
+            let Some(line) = lines.next() else { break };
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            shares.push(slip39::decode_share(line.trim())?);
+            remaining_lines.push(line);
+        }
+        return recover_command_slip39(&remaining_lines, passphrase, language);
+    }
+
+    if first_line.trim().starts_with(ENCRYPTED_SHARD_PREFIX) {
+        let hex = first_line
+            .trim()
+            .strip_prefix(ENCRYPTED_SHARD_PREFIX)
+            .ok_or_else(|| anyhow!("missing {} prefix", ENCRYPTED_SHARD_PREFIX))?;
+        let (header, _) = parse_shard_header_bytes(&decode_hex(hex)?)?;
+        let mut remaining_lines = vec![first_line];
+        while (remaining_lines.len() as u8) < header.threshold {
+            let Some(line) = lines.next() else { break };
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            remaining_lines.push(line);
+        }
+        return recover_command_encrypted(&remaining_lines, passphrase, language);
+    }
+    if encrypt {
+        return Err(anyhow!(
+            "--encrypt was given but the shards aren't in the {}-prefixed encrypted format",
+            ENCRYPTED_SHARD_PREFIX
+        ));
+    }
+
+    let mut remaining_lines = Vec::new();
+    let mut group_id = None;
+    let mut needed = None;
+    for line in std::iter::once(Ok(first_line)).chain(lines) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (header, _) = split_shard_header(line.trim())?;
+
+        match group_id {
+            None => group_id = Some(header.group_id),
+            Some(expected) if expected != header.group_id => {
+                return Err(anyhow!(
+                    "Shard belongs to a different shard set (expected group {:04x}, got {:04x})",
+                    expected,
+                    header.group_id
+                ));
+            }
+            _ => {}
+        }
+        let needed = *needed.get_or_insert(header.threshold);
+
+        remaining_lines.push(line);
+        if remaining_lines.len() as u8 >= needed {
+            break;
+        }
+    }
+
+    recover_command(&remaining_lines)
+}
+
+fn split_command_slip39(
+    seed_phrase: &str,
+    num_shards: u8,
+    threshold: u8,
+    passphrase: &str,
+    language: bip39::Language,
+) -> Result<Vec<String>> {
+    if threshold > num_shards {
+        return Err(anyhow!("Threshold cannot be greater than the number of shards"));
+    }
+
+    let entropy = bip39::Mnemonic::parse_in(language, seed_phrase)
+        .map_err(|e| anyhow!("Invalid seed phrase: {}", e))?
+        .to_entropy();
+
+    // A single group with group threshold 1 mirrors the flat `-n`/`-t` shape
+    // of the sharks scheme; `slip39::split` supports multiple groups for
+    // callers who build their own `GroupParams`.
+    let groups = [slip39::GroupParams {
+        member_threshold: threshold,
+        member_count: num_shards,
+    }];
+    let shares = slip39::split(&entropy, passphrase.as_bytes(), 1, &groups, 0, false)?;
+    shares.iter().map(slip39::encode_share).collect()
+}
+
+fn recover_command_slip39(lines: &[String], passphrase: &str, language: bip39::Language) -> Result<String> {
+    let shares: Result<Vec<_>> = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| slip39::decode_share(line))
+        .collect();
+    let entropy = slip39::recover(&shares?, passphrase.as_bytes())?;
+
+    let mnemonic = bip39::Mnemonic::from_entropy_in(language, &entropy)
+        .map_err(|e| anyhow!("Failed to convert recovered secret to phrase: {}", e))?;
+    Ok(mnemonic.to_string())
+}
+
+/// A shard's self-describing header: which Shamir x-coordinate it is, the
+/// threshold needed to recover the secret, and a random id shared by every
+/// shard in the same `split` run so unrelated shards can be told apart.
+struct ShardHeader {
+    index: u8,
+    threshold: u8,
+    group_id: u16,
+}
+
+const SHARD_HEADER_LEN: usize = 4;
+
+fn parse_shard_header_bytes(bytes: &[u8]) -> Result<(ShardHeader, Vec<u8>)> {
+    if bytes.len() <= SHARD_HEADER_LEN {
+        return Err(anyhow!("Shard is too short to contain a shard header"));
+    }
+    let (header, share_bytes) = bytes.split_at(SHARD_HEADER_LEN);
+    Ok((
+        ShardHeader {
+            index: header[0],
+            threshold: header[1],
+            group_id: u16::from_be_bytes([header[2], header[3]]),
+        },
+        share_bytes.to_vec(),
+    ))
 }
 
-#[derive(Clone, Debug)]
-struct MnemonicShard {
-    pub index: u8,
-    pub mnemonic: bip39::Mnemonic,
+/// Splits a plain sharks shard line into its header and the BIP39 mnemonic
+/// text that follows it. Unlike `parse_shard_header_bytes`, the header here
+/// sits outside the BIP39 entropy, hex-encoded and space-separated from the
+/// phrase, so the mnemonic stays a full standard-length BIP39 phrase
+/// (including a 24-word one) instead of being shortened to make room for it.
+fn split_shard_header(line: &str) -> Result<(ShardHeader, &str)> {
+    let (hex, mnemonic_text) = line
+        .split_once(' ')
+        .ok_or_else(|| anyhow!("Shard is missing its header"))?;
+    let bytes = decode_hex(hex)?;
+    if bytes.len() != SHARD_HEADER_LEN {
+        return Err(anyhow!("Shard header must be {} bytes", SHARD_HEADER_LEN));
+    }
+    Ok((
+        ShardHeader {
+            index: bytes[0],
+            threshold: bytes[1],
+            group_id: u16::from_be_bytes([bytes[2], bytes[3]]),
+        },
+        mnemonic_text,
+    ))
 }
 
-fn generate_command() -> Result<String> {
+fn generate_command(language: bip39::Language) -> Result<String> {
     // Generate 32 bytes of random data using system RNG
     let mut entropy = [0u8; 32];
     getrandom::getrandom(&mut entropy)
         .map_err(|e| anyhow!("Failed to generate random entropy: {}", e))?;
-    
+
     // Convert to BIP39 mnemonic
-    let mnemonic = bip39::Mnemonic::from_entropy(&entropy)
+    let mnemonic = bip39::Mnemonic::from_entropy_in(language, &entropy)
         .map_err(|e| anyhow!("Failed to create mnemonic: {}", e))?;
 
     Ok(mnemonic.to_string())
 }
 
-fn split_command(seed_phrase: &str, num_shards: u8, threshold: u8) -> Result<Vec<MnemonicShard>> {
+fn random_group_id() -> Result<u16> {
+    let mut buf = [0u8; 2];
+    getrandom::getrandom(&mut buf).map_err(|e| anyhow!("Failed to generate group id: {}", e))?;
+    Ok(u16::from_be_bytes(buf))
+}
+
+fn split_command(seed_phrase: &str, num_shards: u8, threshold: u8, language: bip39::Language) -> Result<Vec<String>> {
     // Validate threshold is not greater than number of shards
     if threshold > num_shards {
         return Err(anyhow!("Threshold cannot be greater than the number of shards"));
     }
 
     // Convert seed phrase to entropy bytes
-    let entropy = bip39::Mnemonic::parse_in(bip39::Language::English, seed_phrase)
+    let entropy = bip39::Mnemonic::parse_in(language, seed_phrase)
         .map_err(|e| anyhow!("Invalid seed phrase: {}", e))?
         .to_entropy();
 
+    let group_id = random_group_id()?;
+
     // Create Shamir shards
     let shares = sharks::Sharks(threshold).dealer(&entropy).take(num_shards as usize);
 
-    // Convert each shard back to a BIP39 phrase and collect into Vec
-    let mut mnemonics = Vec::new();
+    // Render each share's value as its own BIP39 phrase, with the
+    // self-describing header carried alongside it (not baked into the
+    // entropy), so every standard seed-phrase length, 24 words included,
+    // can use this scheme.
+    let mut shards = Vec::new();
     for share in shares {
         let share_bytes: Vec<u8> = share.y.iter().map(|b| b.0).collect();
-        let mnemonic = bip39::Mnemonic::from_entropy(&share_bytes)
+        let mnemonic = bip39::Mnemonic::from_entropy_in(language, &share_bytes)
             .map_err(|e| anyhow!("Failed to convert shard {} to phrase: {}", share.x.0, e))?;
-        mnemonics.push(MnemonicShard {
-            index: share.x.0,
-            mnemonic,
-        });
+
+        let mut header_bytes = vec![share.x.0, threshold];
+        header_bytes.extend_from_slice(&group_id.to_be_bytes());
+        shards.push(format!("{} {}", encode_hex(&header_bytes), mnemonic));
     }
-    Ok(mnemonics)
+    Ok(shards)
 }
 
-fn recover_command(shards: &[MnemonicShard]) -> Result<String> {
-    // Convert BIP39 phrases back to bytes
+fn recover_command(lines: &[String]) -> Result<String> {
+    // Parse each shard's self-describing header back out and rebuild the
+    // Shamir share, tracking the threshold the shards themselves claim
+    // rather than trusting that every shard the caller gathered was enough.
     let mut shares = Vec::new();
-    for shard in shards {
-        let mnemonic = bip39::Mnemonic::parse_in(bip39::Language::English, &shard.mnemonic.to_string())
-            .map_err(|e| anyhow!("Invalid shard {}: {}", shard.index, e))?;
+    let mut group_id = None;
+    let mut threshold = None;
+    let mut language = None;
+    for line in lines {
+        let line = line.trim();
+        let (header, mnemonic_text) = split_shard_header(line)?;
+        let mnemonic = bip39::Mnemonic::parse(mnemonic_text.trim()).map_err(describe_bip39_error)?;
+        language.get_or_insert(mnemonic.language());
+
+        match group_id {
+            None => group_id = Some(header.group_id),
+            Some(expected) if expected != header.group_id => {
+                return Err(anyhow!(
+                    "Shard belongs to a different shard set (expected group {:04x}, got {:04x})",
+                    expected,
+                    header.group_id
+                ));
+            }
+            _ => {}
+        }
+        threshold = Some(threshold.unwrap_or(header.threshold).max(header.threshold));
 
-        let mut bytes: Vec<u8> = Vec::new();
-        bytes.push(shard.index);
-        bytes.extend(mnemonic.to_entropy().iter().map(|b| *b));
+        let mut bytes = vec![header.index];
+        bytes.extend_from_slice(&mnemonic.to_entropy());
         let share = sharks::Share::try_from(bytes.as_slice()).map_err(|e| anyhow!("Failed to convert shard bytes: {}", e))?;
         shares.push(share);
     }
 
+    let threshold = threshold.ok_or_else(|| anyhow!("No shards provided"))?;
+    if (shares.len() as u8) < threshold {
+        return Err(anyhow!("need {} shards, only have {}", threshold, shares.len()));
+    }
+
     // Recover the original secret
-    let recovered = sharks::Sharks(shares.len() as u8)
+    let recovered = sharks::Sharks(threshold)
         .recover(&shares)
         .map_err(|e| anyhow!("Failed to recover secret: {}", e))?;
 
-    // Convert recovered bytes back to seed phrase
-    let mnemonic = bip39::Mnemonic::from_entropy(&recovered)
+    // Render the recovered secret in whichever language the shards were in,
+    // so a holder who split in e.g. Japanese sees their phrase come back the same way.
+    let language = language.unwrap_or(bip39::Language::English);
+    let mnemonic = bip39::Mnemonic::from_entropy_in(language, &recovered)
         .map_err(|e| anyhow!("Failed to convert recovered secret to phrase: {}", e))?;
-    
+
     Ok(mnemonic.to_string())
 }
 
+/// Prefix marking a shard as an AES-256-GCM-sealed sharks share, hex-encoded
+/// rather than rendered as a BIP39 phrase: once the nonce and auth tag are
+/// folded in, the sealed payload no longer fits a valid BIP39 entropy length.
+const ENCRYPTED_SHARD_PREFIX: &str = "enc:";
+
+const AES_GCM_NONCE_LEN: usize = 12;
+const KEY_DERIVATION_SALT_LEN: usize = 16;
+/// Matches the total PBKDF2 iterations `slip39`'s four Feistel rounds spend
+/// together at iteration exponent 0 (2,500 each), so guessing a passphrase
+/// against either path costs about the same amount of work per guess.
+const KEY_DERIVATION_ITERATIONS: u32 = 10_000;
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, KEY_DERIVATION_ITERATIONS, &mut key)
+        .expect("32 bytes is a valid PBKDF2-HMAC-SHA256 output length");
+    key
+}
+
+/// Seals `entropy` as `salt || nonce || ciphertext || tag` under a key
+/// derived from `passphrase` and a fresh random salt, so the Shamir split
+/// below never sees the secret in the clear.
+fn seal_entropy(entropy: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let mut salt = [0u8; KEY_DERIVATION_SALT_LEN];
+    getrandom::getrandom(&mut salt).map_err(|e| anyhow!("Failed to generate salt: {}", e))?;
+    let cipher = Aes256Gcm::new((&derive_key(passphrase, &salt)).into());
+    let mut nonce_bytes = [0u8; AES_GCM_NONCE_LEN];
+    getrandom::getrandom(&mut nonce_bytes).map_err(|e| anyhow!("Failed to generate nonce: {}", e))?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), entropy)
+        .map_err(|e| anyhow!("Failed to encrypt entropy: {}", e))?;
+
+    let mut sealed = salt.to_vec();
+    sealed.extend_from_slice(&nonce_bytes);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+fn open_entropy(sealed: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    if sealed.len() <= KEY_DERIVATION_SALT_LEN + AES_GCM_NONCE_LEN {
+        return Err(anyhow!("Encrypted payload is too short"));
+    }
+    let (salt, rest) = sealed.split_at(KEY_DERIVATION_SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(AES_GCM_NONCE_LEN);
+    let cipher = Aes256Gcm::new((&derive_key(passphrase, salt)).into());
+    cipher
+        .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|_| anyhow!("Failed to decrypt shards: wrong passphrase or corrupted shards"))
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.is_ascii() {
+        return Err(anyhow!("Encrypted shard contains invalid hex"));
+    }
+    if !hex.len().is_multiple_of(2) {
+        return Err(anyhow!("Encrypted shard has an odd number of hex digits"));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| anyhow!("Invalid hex in encrypted shard: {}", e)))
+        .collect()
+}
+
+fn split_command_encrypted(
+    seed_phrase: &str,
+    num_shards: u8,
+    threshold: u8,
+    passphrase: &str,
+    language: bip39::Language,
+) -> Result<Vec<String>> {
+    if threshold > num_shards {
+        return Err(anyhow!("Threshold cannot be greater than the number of shards"));
+    }
+
+    let entropy = bip39::Mnemonic::parse_in(language, seed_phrase)
+        .map_err(|e| anyhow!("Invalid seed phrase: {}", e))?
+        .to_entropy();
+    let sealed = seal_entropy(&entropy, passphrase)?;
+    let group_id = random_group_id()?;
+
+    let shares = sharks::Sharks(threshold).dealer(&sealed).take(num_shards as usize);
+    let mut shards = Vec::new();
+    for share in shares {
+        let mut bytes = vec![share.x.0, threshold];
+        bytes.extend_from_slice(&group_id.to_be_bytes());
+        bytes.extend(share.y.iter().map(|b| b.0));
+        shards.push(format!("{}{}", ENCRYPTED_SHARD_PREFIX, encode_hex(&bytes)));
+    }
+    Ok(shards)
+}
+
+fn recover_command_encrypted(lines: &[String], passphrase: &str, language: bip39::Language) -> Result<String> {
+    let mut shares = Vec::new();
+    let mut group_id = None;
+    let mut threshold = None;
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let hex = line
+            .strip_prefix(ENCRYPTED_SHARD_PREFIX)
+            .ok_or_else(|| anyhow!("Expected an {}-prefixed encrypted shard", ENCRYPTED_SHARD_PREFIX))?;
+        let (header, share_bytes) = parse_shard_header_bytes(&decode_hex(hex)?)?;
+
+        match group_id {
+            None => group_id = Some(header.group_id),
+            Some(expected) if expected != header.group_id => {
+                return Err(anyhow!(
+                    "Shard belongs to a different shard set (expected group {:04x}, got {:04x})",
+                    expected,
+                    header.group_id
+                ));
+            }
+            _ => {}
+        }
+        threshold = Some(threshold.unwrap_or(header.threshold).max(header.threshold));
+
+        let mut bytes = vec![header.index];
+        bytes.extend_from_slice(&share_bytes);
+        let share = sharks::Share::try_from(bytes.as_slice()).map_err(|e| anyhow!("Failed to convert shard bytes: {}", e))?;
+        shares.push(share);
+    }
+
+    let threshold = threshold.ok_or_else(|| anyhow!("No shards provided"))?;
+    if (shares.len() as u8) < threshold {
+        return Err(anyhow!("need {} shards, only have {}", threshold, shares.len()));
+    }
+
+    let sealed = sharks::Sharks(threshold)
+        .recover(&shares)
+        .map_err(|e| anyhow!("Failed to recover secret: {}", e))?;
+    let entropy = open_entropy(&sealed, passphrase)?;
+
+    let mnemonic = bip39::Mnemonic::from_entropy_in(language, &entropy)
+        .map_err(|e| anyhow!("Failed to convert recovered secret to phrase: {}", e))?;
+    Ok(mnemonic.to_string())
+}
+
+/// Prints each shard's text, and additionally its QR code to the terminal
+/// and/or an image file, per `qr`.
+fn emit_shards(shard_texts: &[String], qr: &QrOutputArgs) -> Result<()> {
+    if let Some(dir) = &qr.out_dir {
+        std::fs::create_dir_all(dir).map_err(|e| anyhow!("Failed to create {}: {}", dir, e))?;
+    }
+
+    for (i, text) in shard_texts.iter().enumerate() {
+        println!("{}", text);
+        if qr.terminal {
+            println!("{}", render_qr_terminal(text)?);
+        }
+        if let Some(dir) = &qr.out_dir {
+            let path = Path::new(dir).join(format!("shard-{}.{}", i + 1, qr.format));
+            write_qr_image(text, &path, &qr.format)?;
+        }
+    }
+    Ok(())
+}
+
+fn render_qr_terminal(text: &str) -> Result<String> {
+    let code = qrcode::QrCode::new(text.as_bytes()).map_err(|e| anyhow!("Failed to encode QR code: {}", e))?;
+    Ok(code.render::<char>().quiet_zone(false).module_dimensions(2, 1).build())
+}
+
+fn write_qr_image(text: &str, path: &Path, format: &str) -> Result<()> {
+    let code = qrcode::QrCode::new(text.as_bytes()).map_err(|e| anyhow!("Failed to encode QR code: {}", e))?;
+    if format == "svg" {
+        let svg = code.render::<qrcode::render::svg::Color>().build();
+        std::fs::write(path, svg).map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    } else {
+        let image = code.render::<image::Luma<u8>>().build();
+        image
+            .save(path)
+            .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Reads a shard's QR code back out of an image file, for the air-gapped
+/// "photograph the QR code, copy the file over" recovery workflow.
+fn read_qr_image(path: &Path) -> Result<String> {
+    let image = image::open(path)
+        .map_err(|e| anyhow!("Failed to open {}: {}", path.display(), e))?
+        .to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare(image);
+    let grid = prepared
+        .detect_grids()
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No QR code found in {}", path.display()))?;
+    let (_, content) = grid
+        .decode()
+        .map_err(|e| anyhow!("Failed to decode QR code in {}: {}", path.display(), e))?;
+    Ok(content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,7 +963,7 @@ mod tests {
         println!("Original: {}", original_phrase);
 
         // Split into shards using the existing function
-        let shards = match split_command(&original_phrase, 5, 3) {
+        let shards = match split_command(&original_phrase, 5, 3, bip39::Language::English) {
             Ok(s) => s,
             Err(e) => {
                 println!("Error: {}", e);
@@ -222,7 +973,7 @@ mod tests {
         println!("Shards: {:?}", shards);
 
         // Recover using just 3 shards
-        let recovered_phrase = match recover_command(&shards[0..3].to_vec()) {
+        let recovered_phrase = match recover_command(&shards[0..3]) {
             Ok(p) => p,
             Err(e) => {
                 println!("Error: {}", e);
@@ -235,12 +986,139 @@ mod tests {
         original_phrase == recovered_phrase
     }
 
+    #[test]
+    fn validate_shard_line_accepts_each_shard_kind() {
+        let phrase = bip39::Mnemonic::from_entropy(&[0u8; 16]).unwrap().to_string();
+
+        let sharks_shard = split_command(&phrase, 5, 3, bip39::Language::English).unwrap()[0].to_string();
+        let (threshold, group_id) = validate_shard_line(&sharks_shard, ShardKind::Sharks).unwrap();
+        assert_eq!(threshold, 3);
+        assert!(group_id.is_some());
+
+        let encrypted_shard = split_command_encrypted(&phrase, 5, 3, "pw", bip39::Language::English).unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let (threshold, group_id) = validate_shard_line(&encrypted_shard, ShardKind::Encrypted).unwrap();
+        assert_eq!(threshold, 3);
+        assert!(group_id.is_some());
+
+        let slip39_shard = split_command_slip39(&phrase, 5, 3, "", bip39::Language::English).unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let (threshold, group_id) = validate_shard_line(&slip39_shard, ShardKind::Slip39).unwrap();
+        assert_eq!(threshold, 3);
+        assert_eq!(group_id, Some(slip39::decode_share(&slip39_shard).unwrap().identifier));
+    }
+
+    #[test]
+    fn split_recover_roundtrip_with_a_24_word_seed_phrase() {
+        let original_mnemonic = bip39::Mnemonic::from_entropy(&[0u8; 32]).unwrap();
+        let original_phrase = original_mnemonic.to_string();
+
+        let shards = split_command(&original_phrase, 5, 3, bip39::Language::English).unwrap();
+        let recovered = recover_command(&shards[0..3]).unwrap();
+
+        assert_eq!(recovered, original_phrase);
+    }
+
+    #[test]
+    fn recover_command_rejects_fewer_shards_than_the_embedded_threshold() {
+        let original_phrase = bip39::Mnemonic::from_entropy(&[0u8; 16]).unwrap().to_string();
+        let shards = split_command(&original_phrase, 5, 3, bip39::Language::English).unwrap();
+
+        assert!(recover_command(&shards[0..2]).is_err());
+        assert!(recover_from_lines(shards[0..2].iter().cloned().map(Ok), "", false, bip39::Language::English).is_err());
+    }
+
+    #[test]
+    fn validate_shard_line_rejects_malformed_input_without_losing_progress() {
+        assert!(validate_shard_line("not a real shard", ShardKind::Sharks).is_err());
+        assert!(validate_shard_line("not a real shard", ShardKind::Slip39).is_err());
+        assert!(validate_shard_line("not a real shard", ShardKind::Encrypted).is_err());
+    }
+
+    #[test]
+    fn qr_image_roundtrip() {
+        let text = "shard one two three four five six seven";
+        let path = std::env::temp_dir().join("bip39-shard-qr-roundtrip-test.png");
+        write_qr_image(text, &path, "png").unwrap();
+        let decoded = read_qr_image(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(decoded, text);
+    }
+
+    #[test]
+    fn encrypted_split_recover_roundtrip() {
+        let original_mnemonic = bip39::Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+        let original_phrase = original_mnemonic.to_string();
+
+        let shards =
+            split_command_encrypted(&original_phrase, 5, 3, "correct horse battery staple", bip39::Language::English)
+                .unwrap();
+        let recovered =
+            recover_command_encrypted(&shards[0..3], "correct horse battery staple", bip39::Language::English)
+                .unwrap();
+
+        assert_eq!(recovered, original_phrase);
+    }
+
+    #[test]
+    fn encrypted_recover_rejects_the_wrong_passphrase() {
+        let original_mnemonic = bip39::Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+        let original_phrase = original_mnemonic.to_string();
+
+        let shards =
+            split_command_encrypted(&original_phrase, 5, 3, "correct horse battery staple", bip39::Language::English)
+                .unwrap();
+        assert!(recover_command_encrypted(&shards[0..3], "wrong passphrase", bip39::Language::English).is_err());
+    }
+
+    #[test]
+    fn recover_command_encrypted_rejects_fewer_shards_than_the_embedded_threshold() {
+        let original_mnemonic = bip39::Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+        let original_phrase = original_mnemonic.to_string();
+
+        let shards =
+            split_command_encrypted(&original_phrase, 5, 3, "correct horse battery staple", bip39::Language::English)
+                .unwrap();
+        assert!(recover_command_encrypted(&shards[0..2], "correct horse battery staple", bip39::Language::English).is_err());
+    }
+
+    #[test]
+    fn slip39_recover_uses_the_requested_language() {
+        let original_mnemonic = bip39::Mnemonic::from_entropy_in(bip39::Language::Japanese, &[0u8; 16]).unwrap();
+        let original_phrase = original_mnemonic.to_string();
+
+        let shards = split_command_slip39(&original_phrase, 5, 3, "", bip39::Language::Japanese).unwrap();
+        let recovered = recover_command_slip39(&shards[0..3], "", bip39::Language::Japanese).unwrap();
+
+        assert_eq!(recovered, original_phrase);
+    }
+
+    #[test]
+    fn encrypted_recover_uses_the_requested_language() {
+        let original_mnemonic = bip39::Mnemonic::from_entropy_in(bip39::Language::Japanese, &[0u8; 16]).unwrap();
+        let original_phrase = original_mnemonic.to_string();
+
+        let shards =
+            split_command_encrypted(&original_phrase, 5, 3, "correct horse", bip39::Language::Japanese).unwrap();
+        let recovered = recover_command_encrypted(&shards[0..3], "correct horse", bip39::Language::Japanese).unwrap();
+
+        assert_eq!(recovered, original_phrase);
+    }
+
+    // 16 bytes is just this quickcheck fixture's chosen secret length; the
+    // self-describing header lives outside the entropy now, so every valid
+    // BIP39 entropy length works equally well (see split_recover_roundtrip_
+    // with_a_24_word_seed_phrase for the other end of that range).
     #[derive(Clone, Debug)]
-    struct SecretBytes([u8; 32]);
+    struct SecretBytes([u8; 16]);
 
     impl quickcheck::Arbitrary for SecretBytes {
         fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-            let mut arr = [0u8; 32];
+            let mut arr = [0u8; 16];
             for byte in arr.iter_mut() {
                 *byte = u8::arbitrary(g);
             }
@@ -250,9 +1128,9 @@ mod tests {
         fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
             let vec: Vec<u8> = self.0.to_vec();
             Box::new(vec.shrink()
-                .filter(|v| v.len() == 32)
+                .filter(|v| v.len() == 16)
                 .map(|v| {
-                    let mut arr = [0u8; 32];
+                    let mut arr = [0u8; 16];
                     arr.copy_from_slice(&v);
                     SecretBytes(arr)
                 }))
@@ -0,0 +1,129 @@
+//! GF(256) arithmetic reduced by the AES/Rijndael polynomial `0x11B`, the
+//! field SLIP-39's Shamir interpolation is defined over. This is a different
+//! field than the `0x11D`-reduced GF(256) the `sharks` crate (and this
+//! crate's plain sharks scheme) uses, so the two are not interchangeable:
+//! SLIP-39 shares must be produced and recovered with the tables below.
+
+const fn generate_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut value: u16 = 1;
+    let mut power = 0usize;
+    while power < 255 {
+        exp[power] = value as u8;
+        log[value as usize] = power as u8;
+        // Multiply by the generator (x + 1), then reduce by x^8+x^4+x^3+x+1.
+        value = (value << 1) ^ value;
+        if value & 0x100 != 0 {
+            value ^= 0x11B;
+        }
+        power += 1;
+    }
+    (exp, log)
+}
+
+const TABLES: ([u8; 255], [u8; 256]) = generate_tables();
+const EXP: [u8; 255] = TABLES.0;
+const LOG: [u8; 256] = TABLES.1;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Gf256(u8);
+
+impl Gf256 {
+    fn zero() -> Self {
+        Gf256(0)
+    }
+
+    fn exp(power: u16) -> Self {
+        Gf256(EXP[(power % 255) as usize])
+    }
+
+    fn log(self) -> Option<u8> {
+        if self.0 == 0 { None } else { Some(LOG[self.0 as usize]) }
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        match (self.log(), rhs.log()) {
+            (Some(a), Some(b)) => Gf256::exp(a as u16 + b as u16),
+            _ => Gf256::zero(),
+        }
+    }
+
+    fn div(self, rhs: Self) -> Self {
+        match (self.log(), rhs.log()) {
+            (Some(a), Some(b)) => Gf256::exp(255 + a as u16 - b as u16),
+            (None, Some(_)) => Gf256::zero(),
+            (_, None) => panic!("division by zero in GF(256)"),
+        }
+    }
+}
+
+/// Evaluates, at `x`, the unique degree-`< points.len()` polynomial over
+/// GF(256) that passes through `points`, applied independently to each byte
+/// position of the (equal-length) values. This is Shamir's secret sharing's
+/// core operation: called with `x` equal to an unused share index it
+/// produces a new share; called with `x` equal to a point already baked into
+/// `points` (SLIP-39's secret/digest indices) it reconstructs that value.
+pub fn interpolate(points: &[(u8, Vec<u8>)], x: u8) -> Vec<u8> {
+    let len = points[0].1.len();
+    (0..len)
+        .map(|byte_index| {
+            let xy: Vec<(u8, u8)> = points.iter().map(|(px, value)| (*px, value[byte_index])).collect();
+            lagrange_interpolate(&xy, x)
+        })
+        .collect()
+}
+
+fn lagrange_interpolate(points: &[(u8, u8)], x: u8) -> u8 {
+    let mut result = Gf256::zero();
+    for &(xi, yi) in points {
+        let mut term = Gf256(yi);
+        for &(xj, _) in points {
+            if xi != xj {
+                term = term.mul(Gf256(x).add(Gf256(xj))).div(Gf256(xi).add(Gf256(xj)));
+            }
+        }
+        result = result.add(term);
+    }
+    result.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Spot-checked against the reference GF(256) (0x11B) log/exp tables
+    // published alongside SLIP-39 reference implementations.
+    #[test]
+    fn tables_match_reference() {
+        assert_eq!(EXP[0], 1);
+        assert_eq!(EXP[1], 3);
+        assert_eq!(EXP[2], 5);
+        assert_eq!(EXP[25], 2);
+        assert_eq!(LOG[1], 0);
+        assert_eq!(LOG[2], 25);
+        assert_eq!(LOG[3], 1);
+    }
+
+    #[test]
+    fn multiplication_is_commutative_and_has_identity() {
+        let a = Gf256(0x53);
+        let b = Gf256(0xCA);
+        // 0x53 and 0xCA are multiplicative inverses of each other in this field.
+        assert_eq!(a.mul(b), Gf256(1));
+        assert_eq!(a.mul(b), b.mul(a));
+        assert_eq!(a.mul(Gf256(1)), a);
+    }
+
+    #[test]
+    fn interpolate_recovers_constant_term() {
+        // p(x) = 0x42 + 0x07*x sampled at x=1 and x=2 (GF(256) add is xor,
+        // and 0x07*2 = 0x0E since doubling under 0x11B never overflows here).
+        let points = vec![(1u8, vec![0x42 ^ 0x07]), (2u8, vec![0x42 ^ 0x0E])];
+        assert_eq!(interpolate(&points, 0), vec![0x42]);
+    }
+}
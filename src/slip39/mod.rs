@@ -0,0 +1,746 @@
+//! A partial implementation of SLIP-0039 ("Shamir's Secret-Sharing for Mnemonic
+//! Codes"), enough to turn a BIP39 master secret into SLIP-39 mnemonics that a
+//! Trezor (or other SLIP-39-aware tooling) can recover.
+//!
+//! The pieces are, bottom-up: GF(256) Shamir interpolation over the SLIP-39
+//! field (see [`gf256`]), an RS1024 checksum over 10-bit words, a 4-round
+//! Feistel cipher that optionally wraps the secret with a passphrase, and a
+//! two-level Shamir split (groups, then members within a group) that reuses
+//! the same `split_secret`/`recover_secret` pair at both levels.
+//!
+//! Verification status: the test suite below only checks self-consistency
+//! (`split`/`recover` and `encode_share`/`decode_share` round-trip against
+//! themselves). None of it asserts byte-for-byte agreement with the official
+//! `vectors.json` published alongside the spec and `trezor/python-shamir-mnemonic`,
+//! because fetching that fixture needs network access this environment
+//! doesn't have. Treat interop with real SLIP-39 tooling (Trezor included) as
+//! unverified until such a vector test is added.
+
+mod gf256;
+mod wordlist;
+
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2;
+use sha2::Sha256;
+
+pub use wordlist::WORDLIST;
+
+const RADIX_BITS: u32 = 10;
+const ID_LENGTH_BITS: u32 = 15;
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+/// Reserved x-coordinates used only as interpolation points while splitting
+/// or recovering a secret; never assigned to an actual output share, so they
+/// stay well clear of the small member/group indices (0..15) real shares use.
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+const DIGEST_LENGTH_BYTES: usize = 4;
+const ROUND_COUNT: u8 = 4;
+const BASE_ITERATION_COUNT: u32 = 10_000;
+/// RS1024 checksum customization strings. The extendable-backup amendment to
+/// SLIP-39 changed the checksum's customization string (but not the Feistel
+/// salt, which instead drops the string entirely for extendable shares, see
+/// `feistel`) so non-extendable and extendable mnemonics never validate
+/// against each other's checksum.
+const CUSTOMIZATION_STRING: &[u8] = b"shamir";
+const CUSTOMIZATION_STRING_EXTENDABLE: &[u8] = b"shamir_extendable";
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_STRING_EXTENDABLE
+    } else {
+        CUSTOMIZATION_STRING
+    }
+}
+
+/// BIP39 entropy sizes (16/20/24/28/32 bytes) are the only share value
+/// lengths we ever split: a group/member share's value is always exactly as
+/// long as the secret it was split from, digest included or not.
+const VALID_VALUE_BYTE_LENGTHS: [usize; 5] = [16, 20, 24, 28, 32];
+/// Header fields per [`encode_share`]/[`decode_share`] (id, extendable,
+/// iteration exponent, group index/threshold/count, member index/threshold)
+/// plus the minimum (128-bit) share value, plus the checksum, in words.
+const MIN_MNEMONIC_LENGTH_WORDS: usize = 4 + 13 + CHECKSUM_LENGTH_WORDS;
+/// `encode_share` packs `group_index`/`member_index` into 4 bits each, so
+/// SLIP-39 caps both group and member counts (and therefore thresholds, which
+/// can never exceed their count) at 16.
+const MAX_SHARE_COUNT: u8 = 16;
+
+/// One SLIP-39 share: a group/member coordinate pair plus the share value,
+/// ready to be encoded as a mnemonic or decoded back from one.
+#[derive(Clone, Debug)]
+pub struct Slip39Share {
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    pub value: Vec<u8>,
+}
+
+/// Per-group layout requested by the caller: how many member shares to create
+/// for the group and how many of them are required to reconstruct it.
+pub struct GroupParams {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+/// Split `secret` into SLIP-39 shares across one or more groups.
+///
+/// `passphrase` may be empty; an empty passphrase still runs the Feistel
+/// cipher (each round's PBKDF2 password is then just the round index), matching the SLIP-39 reference behavior of
+/// always being able to derive a master secret, just a different one without
+/// the right passphrase.
+pub fn split(
+    secret: &[u8],
+    passphrase: &[u8],
+    group_threshold: u8,
+    groups: &[GroupParams],
+    iteration_exponent: u8,
+    extendable: bool,
+) -> Result<Vec<Slip39Share>> {
+    if !secret.len().is_multiple_of(2) {
+        return Err(anyhow!("SLIP-39 secrets must have an even byte length"));
+    }
+    if group_threshold as usize > groups.len() {
+        return Err(anyhow!("group threshold cannot exceed the number of groups"));
+    }
+    if groups.len() > MAX_SHARE_COUNT as usize {
+        return Err(anyhow!("SLIP-39 supports at most {} groups", MAX_SHARE_COUNT));
+    }
+    for group in groups {
+        if group.member_count > MAX_SHARE_COUNT {
+            return Err(anyhow!("SLIP-39 supports at most {} member shares per group", MAX_SHARE_COUNT));
+        }
+    }
+
+    let identifier = random_identifier()?;
+    let encrypted = encrypt(secret, passphrase, identifier, iteration_exponent, extendable);
+
+    let group_shares = split_secret(&encrypted, group_threshold, groups.len() as u8)?;
+
+    let mut shares = Vec::new();
+    for (group_index, group_secret) in group_shares {
+        let group = &groups[group_index as usize];
+        let member_shares = split_secret(&group_secret, group.member_threshold, group.member_count)?;
+        for (member_index, value) in member_shares {
+            shares.push(Slip39Share {
+                identifier,
+                extendable,
+                iteration_exponent,
+                group_index,
+                group_threshold,
+                group_count: groups.len() as u8,
+                member_index,
+                member_threshold: group.member_threshold,
+                value,
+            });
+        }
+    }
+    Ok(shares)
+}
+
+/// Recover the original secret from a set of shares spanning enough groups
+/// and, within each of those groups, enough members.
+pub fn recover(shares: &[Slip39Share], passphrase: &[u8]) -> Result<Vec<u8>> {
+    let first = shares.first().ok_or_else(|| anyhow!("no shares provided"))?;
+    let identifier = first.identifier;
+    let extendable = first.extendable;
+    let iteration_exponent = first.iteration_exponent;
+    let group_threshold = first.group_threshold;
+    for share in shares {
+        if share.identifier != identifier
+            || share.group_threshold != group_threshold
+            || share.extendable != extendable
+            || share.iteration_exponent != iteration_exponent
+        {
+            return Err(anyhow!("shares belong to different SLIP-39 sets"));
+        }
+    }
+
+    let mut by_group: std::collections::BTreeMap<u8, Vec<&Slip39Share>> = std::collections::BTreeMap::new();
+    for share in shares {
+        by_group.entry(share.group_index).or_default().push(share);
+    }
+
+    let mut group_secrets = Vec::new();
+    for (group_index, members) in &by_group {
+        let member_threshold = members[0].member_threshold;
+        if members.len() < member_threshold as usize {
+            // This group hasn't reached its own member threshold; skip it
+            // rather than letting recover_secret abort the whole function,
+            // since enough *other* groups may still satisfy group_threshold.
+            continue;
+        }
+        let member_shares: Vec<(u8, Vec<u8>)> = members.iter().map(|m| (m.member_index, m.value.clone())).collect();
+        let secret = recover_secret(&member_shares, member_threshold)?;
+        group_secrets.push((*group_index, secret));
+        if group_secrets.len() == group_threshold as usize {
+            break;
+        }
+    }
+    if group_secrets.len() < group_threshold as usize {
+        return Err(anyhow!(
+            "need shares from {} groups, only have {}",
+            group_threshold,
+            group_secrets.len()
+        ));
+    }
+
+    let encrypted = recover_secret(&group_secrets, group_threshold)?;
+    Ok(decrypt(&encrypted, passphrase, identifier, iteration_exponent, extendable))
+}
+
+/// Per-group member index sets seen so far, alongside the member threshold
+/// reported by that group's shares, keyed by group index.
+fn group_member_counts(shares: &[Slip39Share]) -> std::collections::BTreeMap<u8, (std::collections::BTreeSet<u8>, u8)> {
+    let mut by_group: std::collections::BTreeMap<u8, (std::collections::BTreeSet<u8>, u8)> = std::collections::BTreeMap::new();
+    for share in shares {
+        let entry = by_group.entry(share.group_index).or_insert_with(|| (std::collections::BTreeSet::new(), share.member_threshold));
+        entry.0.insert(share.member_index);
+    }
+    by_group
+}
+
+/// Returns true once `shares` contain enough members in enough groups to
+/// satisfy the two-level threshold [`recover`] requires, without performing
+/// the actual Shamir interpolation. Lets callers stop collecting shares as
+/// soon as a multi-group SLIP-39 backup is actually complete, rather than
+/// after a single flat count.
+pub fn shares_satisfy_threshold(shares: &[Slip39Share]) -> bool {
+    let Some(group_threshold) = shares.first().map(|s| s.group_threshold) else {
+        return false;
+    };
+    let satisfied_groups = group_member_counts(shares)
+        .values()
+        .filter(|(members, member_threshold)| members.len() as u8 >= *member_threshold)
+        .count();
+    satisfied_groups as u8 >= group_threshold
+}
+
+/// A short status line for interactive prompts: how many members the most
+/// recently entered share's group has, and how many groups overall are
+/// satisfied so far.
+pub fn progress_summary(shares: &[Slip39Share]) -> String {
+    let Some(last) = shares.last() else {
+        return "0 shares".to_string();
+    };
+    let by_group = group_member_counts(shares);
+    let satisfied_groups = by_group
+        .values()
+        .filter(|(members, member_threshold)| members.len() as u8 >= *member_threshold)
+        .count();
+    let (members, member_threshold) = &by_group[&last.group_index];
+    format!(
+        "group {}: {}/{} members, {}/{} groups satisfied",
+        last.group_index,
+        members.len(),
+        member_threshold,
+        satisfied_groups,
+        last.group_threshold
+    )
+}
+
+/// Split `secret` into `count` GF(256) Shamir shares at x-coordinates
+/// `0..count`, any `threshold` of which reconstruct it. Used at both the
+/// group level (splitting the encrypted master secret across groups) and the
+/// member level (splitting a group's secret across its members).
+///
+/// When `threshold` is 1 every share is just a copy of the secret, matching
+/// the SLIP-39 reference behavior of skipping the digest machinery entirely
+/// in that case. Otherwise `threshold - 2` shares are random, a digest of the
+/// secret and the secret itself are treated as virtual shares at the
+/// reserved [`DIGEST_INDEX`]/[`SECRET_INDEX`] x-coordinates, and the
+/// remaining output shares are produced by Lagrange-interpolating through
+/// all of the above.
+fn split_secret(secret: &[u8], threshold: u8, count: u8) -> Result<Vec<(u8, Vec<u8>)>> {
+    if threshold == 0 || threshold > count {
+        return Err(anyhow!("threshold must be between 1 and the share count"));
+    }
+    if threshold == 1 {
+        return Ok((0..count).map(|i| (i, secret.to_vec())).collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares: Vec<(u8, Vec<u8>)> = Vec::new();
+    for i in 0..random_share_count {
+        let mut value = vec![0u8; secret.len()];
+        getrandom::getrandom(&mut value).map_err(|e| anyhow!("failed to generate random share: {}", e))?;
+        shares.push((i, value));
+    }
+
+    let mut random_pad = vec![0u8; secret.len() - DIGEST_LENGTH_BYTES];
+    getrandom::getrandom(&mut random_pad).map_err(|e| anyhow!("failed to generate digest padding: {}", e))?;
+    let mut digest_value = share_digest(secret, &random_pad);
+    digest_value.extend_from_slice(&random_pad);
+
+    let mut interpolation_points = shares.clone();
+    interpolation_points.push((DIGEST_INDEX, digest_value));
+    interpolation_points.push((SECRET_INDEX, secret.to_vec()));
+
+    for i in random_share_count..count {
+        let value = gf256::interpolate(&interpolation_points, i);
+        shares.push((i, value));
+    }
+    Ok(shares)
+}
+
+/// Inverse of [`split_secret`]: reconstructs the secret from `threshold`
+/// shares by Lagrange-interpolating at [`SECRET_INDEX`], then (for
+/// `threshold > 1`) checks the shares agree by interpolating the digest at
+/// [`DIGEST_INDEX`] and comparing it against a fresh digest of the recovered
+/// secret.
+fn recover_secret(shares: &[(u8, Vec<u8>)], threshold: u8) -> Result<Vec<u8>> {
+    if shares.len() < threshold as usize {
+        return Err(anyhow!("need {} shares, only have {}", threshold, shares.len()));
+    }
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+
+    let secret = gf256::interpolate(shares, SECRET_INDEX);
+    let digest_value = gf256::interpolate(shares, DIGEST_INDEX);
+    let (digest, random_pad) = digest_value.split_at(DIGEST_LENGTH_BYTES);
+    if share_digest(&secret, random_pad) != digest {
+        return Err(anyhow!("SLIP-39 digest mismatch: shares do not agree on the secret"));
+    }
+    Ok(secret)
+}
+
+fn share_digest(secret: &[u8], random_pad: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(random_pad).expect("HMAC accepts any key length");
+    mac.update(secret);
+    mac.finalize().into_bytes()[..4].to_vec()
+}
+
+fn random_identifier() -> Result<u16> {
+    let mut buf = [0u8; 2];
+    getrandom::getrandom(&mut buf).map_err(|e| anyhow!("failed to generate share identifier: {}", e))?;
+    Ok(u16::from_be_bytes(buf) & ((1 << ID_LENGTH_BITS) - 1))
+}
+
+fn encrypt(secret: &[u8], passphrase: &[u8], identifier: u16, iteration_exponent: u8, extendable: bool) -> Vec<u8> {
+    feistel(secret, passphrase, identifier, iteration_exponent, extendable, false)
+}
+
+fn decrypt(secret: &[u8], passphrase: &[u8], identifier: u16, iteration_exponent: u8, extendable: bool) -> Vec<u8> {
+    feistel(secret, passphrase, identifier, iteration_exponent, extendable, true)
+}
+
+fn feistel(secret: &[u8], passphrase: &[u8], identifier: u16, iteration_exponent: u8, extendable: bool, decrypt: bool) -> Vec<u8> {
+    let half = secret.len() / 2;
+    let (mut l, mut r) = (secret[..half].to_vec(), secret[half..].to_vec());
+
+    let mut salt = Vec::new();
+    if !extendable {
+        salt.extend_from_slice(CUSTOMIZATION_STRING);
+        salt.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+
+    let rounds: Vec<u8> = if decrypt {
+        (0..ROUND_COUNT).rev().collect()
+    } else {
+        (0..ROUND_COUNT).collect()
+    };
+    for round in rounds {
+        let f = round_function(round, passphrase, &salt, &r, iterations);
+        let next_r = xor(&l, &f);
+        l = r;
+        r = next_r;
+    }
+    let mut out = r;
+    out.extend_from_slice(&l);
+    out
+}
+
+fn round_function(round: u8, passphrase: &[u8], salt: &[u8], r: &[u8], iterations: u32) -> Vec<u8> {
+    let mut password = vec![round];
+    password.extend_from_slice(passphrase);
+    let mut pbkdf2_salt = salt.to_vec();
+    pbkdf2_salt.extend_from_slice(r);
+    let mut output = vec![0u8; r.len()];
+    pbkdf2::<Hmac<Sha256>>(&password, &pbkdf2_salt, iterations, &mut output)
+        .expect("PBKDF2-HMAC-SHA256 output length is always valid for a half-secret");
+    output
+}
+
+fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).map(|(x, y)| x ^ y).collect()
+}
+
+/// Pack a share's fields into 10-bit words, append the RS1024 checksum, and
+/// render the whole thing as a mnemonic.
+pub fn encode_share(share: &Slip39Share) -> Result<String> {
+    let mut bits = BitWriter::new();
+    bits.push(share.identifier as u32, ID_LENGTH_BITS);
+    bits.push(share.extendable as u32, 1);
+    bits.push(share.iteration_exponent as u32, 4);
+    bits.push(share.group_index as u32, 4);
+    bits.push((share.group_threshold - 1) as u32, 4);
+    bits.push((share.group_count - 1) as u32, 4);
+    bits.push(share.member_index as u32, 4);
+    bits.push((share.member_threshold - 1) as u32, 4);
+    bits.push_bytes_padded(&share.value, RADIX_BITS);
+
+    let mut words = bits.into_words();
+    let checksum = rs1024_create_checksum(&words, share.extendable);
+    words.extend_from_slice(&checksum);
+    words
+        .iter()
+        .map(|w| WORDLIST.get(*w as usize).copied().ok_or_else(|| anyhow!("word index out of range")))
+        .collect::<Result<Vec<_>>>()
+        .map(|words| words.join(" "))
+}
+
+/// Inverse of [`encode_share`]: parse a mnemonic back into share fields,
+/// verifying the RS1024 checksum along the way.
+pub fn decode_share(mnemonic: &str) -> Result<Slip39Share> {
+    let words: Result<Vec<u16>> = mnemonic
+        .split_whitespace()
+        .map(|w| {
+            WORDLIST
+                .iter()
+                .position(|candidate| *candidate == w)
+                .map(|i| i as u16)
+                .ok_or_else(|| anyhow!("'{}' is not a SLIP-39 wordlist word", w))
+        })
+        .collect();
+    let words = words?;
+    if words.len() < MIN_MNEMONIC_LENGTH_WORDS {
+        return Err(anyhow!("SLIP-39 mnemonic is too short"));
+    }
+    let payload = &words[..words.len() - CHECKSUM_LENGTH_WORDS];
+
+    // The checksum's customization string depends on the extendable flag
+    // (see `customization_string`), so that bit has to be read out of the
+    // payload before the checksum can be verified.
+    let mut bits = BitReader::new(payload);
+    let identifier = bits.take(ID_LENGTH_BITS) as u16;
+    let extendable = bits.take(1) != 0;
+    if !rs1024_verify_checksum(&words, extendable) {
+        return Err(anyhow!("SLIP-39 checksum mismatch"));
+    }
+    let iteration_exponent = bits.take(4) as u8;
+    let group_index = bits.take(4) as u8;
+    let group_threshold = bits.take(4) as u8 + 1;
+    let group_count = bits.take(4) as u8 + 1;
+    let member_index = bits.take(4) as u8;
+    let member_threshold = bits.take(4) as u8 + 1;
+    let value = bits.take_remaining_bytes()?;
+
+    Ok(Slip39Share {
+        identifier,
+        extendable,
+        iteration_exponent,
+        group_index,
+        group_threshold,
+        group_count,
+        member_index,
+        member_threshold,
+        value,
+    })
+}
+
+/// Returns `true` if `mnemonic` decodes as a complete share: SLIP-39 wordlist
+/// words only, at least the minimum length, a valid RS1024 checksum and a
+/// supported share value length. Used by `recover` to distinguish a
+/// SLIP-39 shard from a plain BIP39 one.
+pub fn looks_like_slip39(mnemonic: &str) -> bool {
+    decode_share(mnemonic).is_ok()
+}
+
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self { bits: Vec::new() }
+    }
+
+    fn push(&mut self, value: u32, width: u32) {
+        for i in (0..width).rev() {
+            self.bits.push((value >> i) & 1 != 0);
+        }
+    }
+
+    /// Appends `bytes`, first inserting just enough leading zero bits so the
+    /// header-plus-value bitstream ends on a whole-word (`radix_bits`)
+    /// boundary, matching the SLIP-39 reference encoder.
+    fn push_bytes_padded(&mut self, bytes: &[u8], radix_bits: u32) {
+        let total = self.bits.len() + bytes.len() * 8;
+        let padded_total = total.div_ceil(radix_bits as usize) * radix_bits as usize;
+        let pad = padded_total - total;
+        self.bits.extend(std::iter::repeat_n(false, pad));
+        for byte in bytes {
+            self.push(*byte as u32, 8);
+        }
+    }
+
+    fn into_words(self) -> Vec<u16> {
+        self.bits
+            .chunks(RADIX_BITS as usize)
+            .map(|chunk| {
+                let mut word = 0u16;
+                for (i, bit) in chunk.iter().enumerate() {
+                    if *bit {
+                        word |= 1 << (chunk.len() - 1 - i);
+                    }
+                }
+                word
+            })
+            .collect()
+    }
+}
+
+struct BitReader<'a> {
+    words: &'a [u16],
+    bit_pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(words: &'a [u16]) -> Self {
+        Self { words, bit_pos: 0 }
+    }
+
+    fn take(&mut self, width: u32) -> u32 {
+        let mut value = 0u32;
+        for _ in 0..width {
+            let word = self.words[self.bit_pos / RADIX_BITS as usize];
+            let bit_in_word = self.bit_pos % RADIX_BITS as usize;
+            let bit = (word >> (RADIX_BITS as usize - 1 - bit_in_word)) & 1;
+            value = (value << 1) | bit as u32;
+            self.bit_pos += 1;
+        }
+        value
+    }
+
+    /// Drops the leading zero padding `push_bytes_padded` inserted and reads
+    /// the rest as whole bytes. The pad width isn't stored anywhere, so it's
+    /// recovered by checking which of the valid share value lengths (the
+    /// BIP39 entropy sizes in `VALID_VALUE_BYTE_LENGTHS`) would have produced
+    /// the current word-aligned position. Returns an error instead of
+    /// panicking if no supported length fits, which a malformed or corrupted
+    /// mnemonic (one that merely happens to carry a valid RS1024 checksum)
+    /// can otherwise trigger.
+    fn take_remaining_bytes(&mut self) -> Result<Vec<u8>> {
+        let total_bits = self.words.len() * RADIX_BITS as usize;
+        let remaining_bits = total_bits - self.bit_pos;
+        let consumed = self.bit_pos;
+        let value_bytes = VALID_VALUE_BYTE_LENGTHS
+            .iter()
+            .copied()
+            .find(|len| {
+                let value_bits = len * 8;
+                let padded = (consumed + value_bits).div_ceil(RADIX_BITS as usize) * RADIX_BITS as usize;
+                padded - consumed == remaining_bits
+            })
+            .ok_or_else(|| anyhow!("share value length does not match a supported BIP39 entropy size"))?;
+        let pad = remaining_bits - value_bytes * 8;
+        self.bit_pos += pad;
+        Ok((0..value_bytes).map(|_| self.take(8) as u8).collect())
+    }
+}
+
+const GEN: [u32; 10] = [
+    0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48, 0x21B1F890, 0x3F3F120,
+];
+
+fn rs1024_polymod(values: &[u16]) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let b = chk >> 20;
+        chk = (chk & 0xFFFFF) << 10 ^ (*value as u32);
+        for (i, g) in GEN.iter().enumerate() {
+            if (b >> i) & 1 != 0 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(data: &[u16], extendable: bool) -> [u16; CHECKSUM_LENGTH_WORDS] {
+    let mut values: Vec<u16> = customization_string(extendable).iter().map(|b| *b as u16).collect();
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0, 0, 0]);
+    let polymod = rs1024_polymod(&values) ^ 1;
+    let mut checksum = [0u16; CHECKSUM_LENGTH_WORDS];
+    for (i, word) in checksum.iter_mut().enumerate() {
+        *word = ((polymod >> (10 * (CHECKSUM_LENGTH_WORDS - 1 - i))) & 1023) as u16;
+    }
+    checksum
+}
+
+fn rs1024_verify_checksum(data: &[u16], extendable: bool) -> bool {
+    let mut values: Vec<u16> = customization_string(extendable).iter().map(|b| *b as u16).collect();
+    values.extend_from_slice(data);
+    rs1024_polymod(&values) == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 16] = [
+        0x0c, 0x94, 0x90, 0x4c, 0x4f, 0x10, 0xc6, 0xd8, 0x43, 0xf3, 0xb4, 0x18, 0x9e, 0xec, 0x0b, 0x07,
+    ];
+
+    fn single_group(member_threshold: u8, member_count: u8) -> Vec<GroupParams> {
+        vec![GroupParams { member_threshold, member_count }]
+    }
+
+    #[test]
+    fn split_recover_roundtrip_single_group() {
+        let groups = single_group(3, 5);
+        let shares = split(&SECRET, b"", 1, &groups, 0, true).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        let recovered = recover(&shares[1..4], b"").unwrap();
+        assert_eq!(recovered, SECRET);
+    }
+
+    #[test]
+    fn split_recover_roundtrip_member_threshold_one() {
+        let groups = single_group(1, 3);
+        let shares = split(&SECRET, b"", 1, &groups, 0, false).unwrap();
+        let recovered = recover(&shares[0..1], b"").unwrap();
+        assert_eq!(recovered, SECRET);
+    }
+
+    #[test]
+    fn split_recover_roundtrip_multiple_groups() {
+        let groups = vec![
+            GroupParams { member_threshold: 2, member_count: 3 },
+            GroupParams { member_threshold: 1, member_count: 1 },
+            GroupParams { member_threshold: 3, member_count: 4 },
+        ];
+        let shares = split(&SECRET, b"correct horse battery staple", 2, &groups, 0, true).unwrap();
+
+        let mut picked = Vec::new();
+        picked.extend(shares.iter().filter(|s| s.group_index == 0).take(2).cloned());
+        picked.extend(shares.iter().filter(|s| s.group_index == 2).take(3).cloned());
+
+        let recovered = recover(&picked, b"correct horse battery staple").unwrap();
+        assert_eq!(recovered, SECRET);
+    }
+
+    #[test]
+    fn split_recover_roundtrip_skips_an_under_satisfied_group() {
+        let groups = vec![
+            GroupParams { member_threshold: 2, member_count: 3 },
+            GroupParams { member_threshold: 1, member_count: 1 },
+            GroupParams { member_threshold: 3, member_count: 4 },
+        ];
+        let shares = split(&SECRET, b"correct horse battery staple", 2, &groups, 0, true).unwrap();
+
+        let mut picked = Vec::new();
+        // A single stray share from group 0 (member threshold 2), which never
+        // reaches its own threshold and must be skipped rather than aborting
+        // recovery...
+        picked.extend(shares.iter().filter(|s| s.group_index == 0).take(1).cloned());
+        // ...while groups 1 and 2 are each fully satisfied and together meet
+        // group_threshold on their own.
+        picked.extend(shares.iter().filter(|s| s.group_index == 1).take(1).cloned());
+        picked.extend(shares.iter().filter(|s| s.group_index == 2).take(3).cloned());
+
+        let recovered = recover(&picked, b"correct horse battery staple").unwrap();
+        assert_eq!(recovered, SECRET);
+    }
+
+    #[test]
+    fn recover_rejects_too_few_member_shares() {
+        let groups = single_group(3, 5);
+        let shares = split(&SECRET, b"", 1, &groups, 0, false).unwrap();
+        assert!(recover(&shares[0..2], b"").is_err());
+    }
+
+    #[test]
+    fn recover_detects_digest_mismatch_when_a_share_value_is_tampered() {
+        let groups = single_group(3, 5);
+        let mut shares = split(&SECRET, b"", 1, &groups, 0, false).unwrap();
+        shares[0].value[0] ^= 0xFF;
+        assert!(recover(&shares[0..3], b"").is_err());
+    }
+
+    #[test]
+    fn split_rejects_member_count_above_the_4_bit_index_limit() {
+        // 17 members would need a member_index of 16, which truncates to 0
+        // when packed into encode_share's 4-bit field, colliding with share 0.
+        let groups = single_group(10, 17);
+        assert!(split(&SECRET, b"", 1, &groups, 0, false).is_err());
+    }
+
+    #[test]
+    fn split_rejects_too_many_groups() {
+        let groups: Vec<GroupParams> = (0..17).map(|_| GroupParams { member_threshold: 1, member_count: 1 }).collect();
+        assert!(split(&SECRET, b"", 1, &groups, 0, false).is_err());
+    }
+
+    #[test]
+    fn encode_decode_share_roundtrip() {
+        let groups = single_group(2, 3);
+        let shares = split(&SECRET, b"", 1, &groups, 2, true).unwrap();
+        for share in &shares {
+            let mnemonic = encode_share(share).unwrap();
+            assert!(looks_like_slip39(&mnemonic));
+            let decoded = decode_share(&mnemonic).unwrap();
+            assert_eq!(decoded.identifier, share.identifier);
+            assert_eq!(decoded.extendable, share.extendable);
+            assert_eq!(decoded.iteration_exponent, share.iteration_exponent);
+            assert_eq!(decoded.group_index, share.group_index);
+            assert_eq!(decoded.group_threshold, share.group_threshold);
+            assert_eq!(decoded.group_count, share.group_count);
+            assert_eq!(decoded.member_index, share.member_index);
+            assert_eq!(decoded.member_threshold, share.member_threshold);
+            assert_eq!(decoded.value, share.value);
+        }
+    }
+
+    #[test]
+    fn rs1024_checksum_uses_a_different_customization_string_when_extendable() {
+        let words: Vec<u16> = (0..13).collect();
+        let non_extendable_checksum = rs1024_create_checksum(&words, false);
+        let extendable_checksum = rs1024_create_checksum(&words, true);
+        assert_ne!(non_extendable_checksum, extendable_checksum);
+
+        let mut as_non_extendable = words.clone();
+        as_non_extendable.extend_from_slice(&non_extendable_checksum);
+        assert!(rs1024_verify_checksum(&as_non_extendable, false));
+        assert!(!rs1024_verify_checksum(&as_non_extendable, true));
+
+        let mut as_extendable = words;
+        as_extendable.extend_from_slice(&extendable_checksum);
+        assert!(rs1024_verify_checksum(&as_extendable, true));
+        assert!(!rs1024_verify_checksum(&as_extendable, false));
+    }
+
+    #[test]
+    fn decode_share_rejects_short_mnemonics_instead_of_panicking() {
+        // 13 wordlist words plus a valid checksum: the checksum alone would
+        // accept it, but at 16 words it is below MIN_MNEMONIC_LENGTH_WORDS,
+        // so decode_share rejects it before reading any share fields.
+        let words: Vec<u16> = (0..13).collect();
+        let checksum = rs1024_create_checksum(&words, false);
+        let mut all = words;
+        all.extend_from_slice(&checksum);
+        let mnemonic = all.iter().map(|w| WORDLIST[*w as usize]).collect::<Vec<_>>().join(" ");
+        assert!(rs1024_verify_checksum(&all, false));
+        assert!(decode_share(&mnemonic).is_err());
+    }
+
+    #[test]
+    fn wordlist_is_sorted_and_unique() {
+        assert_eq!(WORDLIST.len(), 1024);
+        let mut sorted = WORDLIST.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 1024);
+        assert_eq!(WORDLIST[0], "academic");
+        assert_eq!(WORDLIST[1023], "zero");
+    }
+}